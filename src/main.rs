@@ -1,17 +1,121 @@
+use std::collections::HashMap;
 use std::process::Command;
+use std::time::{Duration, Instant};
 use std::{fmt, str::from_utf8};
 
+use async_trait::async_trait;
+use bb8::Pool;
 use bollard::models::{ContainerInspectResponse, ContainerState as BollardContainerState};
 use bollard::Docker;
 use clap::{Parser, Subcommand};
 use color_print::cprintln;
 use futures_util::StreamExt;
-use redis::{self, Client, Commands, RedisResult};
+use redis::{self, aio::ConnectionManager, AsyncCommands, Client};
 use serde::{Deserialize, Serialize};
 use sqlx::migrate::MigrateDatabase;
 use sqlx::pool::PoolConnection;
 use sqlx::Sqlite;
 
+mod tui;
+
+/// bb8 manager for a pooled `redis::aio::ConnectionManager`, so Redis hiccups surface as a
+/// failed pool checkout instead of a single shared connection going bad for everyone
+struct RedisConnectionManager {
+    client: Client,
+}
+
+#[async_trait]
+impl bb8::ManageConnection for RedisConnectionManager {
+    type Connection = ConnectionManager;
+    type Error = redis::RedisError;
+
+    async fn connect(&self) -> Result<Self::Connection, Self::Error> {
+        self.client.get_connection_manager().await
+    }
+
+    async fn is_valid(&self, conn: &mut Self::Connection) -> Result<(), Self::Error> {
+        redis::cmd("PING").query_async(conn).await
+    }
+
+    fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+        false
+    }
+}
+
+type RedisPool = Pool<RedisConnectionManager>;
+
+/// the CPU/memory/restart limits past which a running container is considered unhealthy
+#[derive(Clone, Copy)]
+struct HealthThresholds {
+    cpu_percent: f32,
+    memory_percent: f32,
+    restart_count: i64,
+}
+
+impl Default for HealthThresholds {
+    fn default() -> Self {
+        Self {
+            cpu_percent: 80.0,
+            memory_percent: 80.0,
+            restart_count: 5,
+        }
+    }
+}
+
+/// dispatches a notification when a container breaches a health threshold; implementations
+/// should keep failures non-fatal to the monitor loop
+#[async_trait]
+trait Alerter {
+    async fn alert(&self, payload: &AlertPayload) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+type SharedAlerter = std::sync::Arc<dyn Alerter + Send + Sync>;
+
+/// JSON payload POSTed to a configured alert endpoint describing the container and the
+/// breached metric
+#[derive(Serialize)]
+struct AlertPayload {
+    container: String,
+    container_state: String,
+    metric: String,
+    value: f64,
+    threshold: f64,
+    timestamp: i64,
+}
+
+/// POSTs `AlertPayload`s to a configured webhook URL (Slack incoming webhooks, Discord,
+/// in-house ops tooling, etc. all accept a plain JSON POST)
+struct WebhookAlerter {
+    url: String,
+    client: reqwest::Client,
+}
+
+impl WebhookAlerter {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(3))
+                .build()
+                .expect("failed to build webhook http client"),
+        }
+    }
+}
+
+#[async_trait]
+impl Alerter for WebhookAlerter {
+    async fn alert(&self, payload: &AlertPayload) -> Result<(), Box<dyn std::error::Error>> {
+        self.client
+            .post(&self.url)
+            .json(payload)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
 #[derive(Parser)]
 #[command(version, about, long_about = None)] // Read from `Cargo.toml`
 struct Cli {
@@ -21,16 +125,53 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum CliCommands {
-    /// monitor specific containers by passing their names
+    /// monitor specific containers by passing their names, or select them by label
     Monitor {
         #[arg(short, long)]
         name: Option<Vec<String>>,
 
+        /// select containers by label instead of name, e.g. `--label app=web` (repeatable)
+        #[arg(short, long)]
+        label: Option<Vec<String>>,
+
+        /// narrow a `--label` selection to containers reporting this docker health status
+        /// (healthy/unhealthy/starting/none)
+        #[arg(long)]
+        health: Option<String>,
+
         #[arg(short, long, default_value_t = 60)]
         cache_ttl: u64, // cache time-to-live in seconds
 
         #[arg(short, long, default_value_t = false)]
         watch: bool,
+
+        /// restart containers that stay unhealthy for longer than `--unhealthy-timeout`
+        #[arg(long, default_value_t = false)]
+        auto_restart: bool,
+
+        /// only auto-restart containers carrying this label
+        #[arg(long, default_value = "auto-restart.unhealthy")]
+        restart_label: String,
+
+        /// how long (in seconds) a container must stay unhealthy before it gets auto-restarted
+        #[arg(long, default_value_t = 35)]
+        unhealthy_timeout: u64,
+
+        /// CPU% above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 80.0)]
+        cpu_threshold: f32,
+
+        /// memory% above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 80.0)]
+        mem_threshold: f32,
+
+        /// restart count above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 5)]
+        restart_threshold: i64,
+
+        /// POST a JSON payload here whenever a container transitions into Unhealthy
+        #[arg(long)]
+        webhook_url: Option<String>,
     },
 
     /// monitor all container on the machine
@@ -40,6 +181,53 @@ enum CliCommands {
 
         #[arg(short, long, default_value_t = false)]
         watch: bool, // BUG: adding watch here, does not watch for newly created containers, only ones which existed when starting the CLI
+
+        /// restart containers that stay unhealthy for longer than `--unhealthy-timeout`
+        #[arg(long, default_value_t = false)]
+        auto_restart: bool,
+
+        /// only auto-restart containers carrying this label
+        #[arg(long, default_value = "auto-restart.unhealthy")]
+        restart_label: String,
+
+        /// how long (in seconds) a container must stay unhealthy before it gets auto-restarted
+        #[arg(long, default_value_t = 35)]
+        unhealthy_timeout: u64,
+
+        /// CPU% above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 80.0)]
+        cpu_threshold: f32,
+
+        /// memory% above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 80.0)]
+        mem_threshold: f32,
+
+        /// restart count above which a running container is considered unhealthy
+        #[arg(long, default_value_t = 5)]
+        restart_threshold: i64,
+
+        /// POST a JSON payload here whenever a container transitions into Unhealthy
+        #[arg(long)]
+        webhook_url: Option<String>,
+    },
+
+    /// interactive full-screen TUI dashboard with CPU/memory sparklines and per-container actions
+    Dashboard {
+        #[arg(short, long)]
+        name: Option<Vec<String>>,
+
+        #[arg(short, long, default_value_t = 2)]
+        refresh_secs: u64,
+    },
+
+    /// view recorded history and summary trends for a container
+    History {
+        #[arg(short, long)]
+        name: String,
+
+        /// how far back to look, in seconds
+        #[arg(short, long, default_value_t = 3600)]
+        since: i64,
     },
 
     /// simply wipe/delete the database file for users who want to start from a clean DB
@@ -124,6 +312,7 @@ struct ContainerHealth {
     memory_percent: f32,
     uptime: String,
     last_updated: i64,
+    labels: HashMap<String, String>,
 }
 
 impl fmt::Display for ContainerHealth {
@@ -166,6 +355,7 @@ impl Default for ContainerHealth {
             memory_percent: 0.0,
             uptime: "".to_string(),
             last_updated: chrono::Utc::now().timestamp(),
+            labels: HashMap::new(),
         }
     }
 }
@@ -174,6 +364,7 @@ impl ContainerHealth {
     pub async fn new(
         container_name: &str,
         docker: &Docker,
+        thresholds: &HealthThresholds,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let inspect_options = bollard::query_parameters::InspectContainerOptions { size: false };
 
@@ -185,6 +376,17 @@ impl ContainerHealth {
 
         let container_state: ContainerState = ContainerState::from(&inspect_result.state);
 
+        let labels = inspect_result
+            .config
+            .as_ref()
+            .and_then(|config| config.labels.clone())
+            .unwrap_or_default();
+
+        let native_health = inspect_result
+            .state
+            .as_ref()
+            .and_then(|state| state.health.clone());
+
         let started_at = inspect_result.state.unwrap().started_at.unwrap();
 
         let uptime = Self::calculate_uptime(&started_at, &container_state).unwrap();
@@ -208,12 +410,23 @@ impl ContainerHealth {
         let cpu_percent = Self::calculate_cpu_percent(&stats_result);
         let (memory_usage, memory_percent) = Self::calculate_memory_stats(&stats_result);
 
-        let status = Self::get_health_status(
-            container_state.to_string().as_str(),
-            cpu_percent,
-            memory_percent,
-            restart_count,
-        );
+        // the container's own HEALTHCHECK is authoritative when defined; only fall back to
+        // the CPU/memory/restart heuristic for containers without one
+        let status = match native_health.as_ref().and_then(Self::native_health_status) {
+            Some(native_status) => {
+                if matches!(native_status, HealthStatus::Unhealthy) {
+                    Self::log_unhealthy_checks(container_name, native_health.as_ref());
+                }
+                native_status
+            }
+            None => Self::get_health_status(
+                container_state.to_string().as_str(),
+                cpu_percent,
+                memory_percent,
+                restart_count,
+                thresholds,
+            ),
+        };
 
         Ok(Self {
             id,
@@ -226,6 +439,7 @@ impl ContainerHealth {
             memory_percent,
             uptime,
             last_updated: chrono::Utc::now().timestamp(),
+            labels,
         })
     }
 
@@ -322,10 +536,14 @@ impl ContainerHealth {
         cpu_percent: f32,
         memory_percent: f32,
         restart_count: i64,
+        thresholds: &HealthThresholds,
     ) -> HealthStatus {
         match container_state {
             "running" => {
-                if restart_count > 5 || cpu_percent > 80.0 || memory_percent > 80.0 {
+                if restart_count > thresholds.restart_count
+                    || cpu_percent > thresholds.cpu_percent
+                    || memory_percent > thresholds.memory_percent
+                {
                     HealthStatus::Unhealthy
                 } else {
                     HealthStatus::Healthy
@@ -337,23 +555,106 @@ impl ContainerHealth {
         }
     }
 
-    fn from_cache(
-        cache_key: &str,
-        redis_conn: &mut redis::Connection,
-    ) -> RedisResult<Option<Self>> {
-        let json_data: Option<String> = redis_conn.get(cache_key)?;
-        Ok(json_data
-            .map(|data| serde_json::from_str(&data).expect("Failed to deserialize cached data")))
+    /// the first threshold a running container breaches, as `(metric name, value, threshold)`,
+    /// or `None` if it's within limits. Used to describe *why* an alert fired.
+    fn breached_metric(
+        cpu_percent: f32,
+        memory_percent: f32,
+        restart_count: i64,
+        thresholds: &HealthThresholds,
+    ) -> Option<(&'static str, f64, f64)> {
+        if restart_count > thresholds.restart_count {
+            Some(("restart_count", restart_count as f64, thresholds.restart_count as f64))
+        } else if cpu_percent > thresholds.cpu_percent {
+            Some(("cpu_percent", cpu_percent as f64, thresholds.cpu_percent as f64))
+        } else if memory_percent > thresholds.memory_percent {
+            Some((
+                "memory_percent",
+                memory_percent as f64,
+                thresholds.memory_percent as f64,
+            ))
+        } else {
+            None
+        }
     }
 
-    fn store_in_cache(&self, redis_conn: &mut redis::Connection, ttl: u64) -> RedisResult<()> {
-        let json_data: String = serde_json::to_string(self).unwrap();
+    /// maps the container's own `HEALTHCHECK` status to a `HealthStatus`, or `None` when the
+    /// container has no healthcheck defined (`NONE`/absent), so the caller falls back to the
+    /// CPU/memory/restart heuristic
+    fn native_health_status(health: &bollard::models::Health) -> Option<HealthStatus> {
+        match health.status {
+            Some(bollard::models::HealthStatusEnum::HEALTHY) => Some(HealthStatus::Healthy),
+            Some(bollard::models::HealthStatusEnum::UNHEALTHY) => Some(HealthStatus::Unhealthy),
+            Some(bollard::models::HealthStatusEnum::STARTING) => Some(HealthStatus::Stall),
+            _ => None,
+        }
+    }
 
-        let cache_key = format!("health-data:{}", self.name);
+    /// prints the last few `HEALTHCHECK` log entries (with exit codes) so a native-unhealthy
+    /// verdict comes with some context instead of a bare status flip
+    fn log_unhealthy_checks(container_name: &str, health: Option<&bollard::models::Health>) {
+        let Some(log) = health.and_then(|health| health.log.as_ref()) else {
+            return;
+        };
 
-        let _: () = redis_conn.set_ex(cache_key, json_data, ttl)?;
+        for entry in log.iter().rev().take(3) {
+            cprintln!(
+                "<red>  {} healthcheck exit {}: {}</red>",
+                container_name,
+                entry.exit_code.unwrap_or(-1),
+                entry.output.as_deref().unwrap_or("").trim()
+            );
+        }
+    }
 
-        Ok(())
+    /// looks up a cached snapshot; any pool/GET/deserialize failure is logged and treated as
+    /// a cache miss so a Redis hiccup never aborts the monitor loop
+    async fn from_cache(cache_key: &str, redis_pool: &RedisPool) -> Option<Self> {
+        let mut conn = match redis_pool.get().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                cprintln!("<yellow>⚠️  redis pool unavailable ({err}), skipping cache</yellow>");
+                return None;
+            }
+        };
+
+        let json_data: Option<String> = match conn.get(cache_key).await {
+            Ok(data) => data,
+            Err(err) => {
+                cprintln!("<yellow>⚠️  redis GET failed ({err}), falling back to live inspect</yellow>");
+                return None;
+            }
+        };
+
+        json_data.and_then(|data| match serde_json::from_str(&data) {
+            Ok(health) => Some(health),
+            Err(err) => {
+                cprintln!(
+                    "<yellow>⚠️  malformed cached data for {cache_key} ({err}), ignoring</yellow>"
+                );
+                None
+            }
+        })
+    }
+
+    /// best-effort cache write: a pool/SET failure is logged and swallowed rather than
+    /// failing the caller, since the cache is an optimization, not a source of truth
+    async fn store_in_cache(&self, redis_pool: &RedisPool, ttl: u64) {
+        let json_data = serde_json::to_string(self).expect("ContainerHealth is always JSON-serializable");
+
+        let mut conn = match redis_pool.get().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                cprintln!("<yellow>⚠️  redis pool unavailable ({err}), skipping cache write</yellow>");
+                return;
+            }
+        };
+
+        let cache_key = format!("health-data:{}", self.name);
+
+        if let Err(err) = conn.set_ex::<_, _, ()>(cache_key, json_data, ttl).await {
+            cprintln!("<yellow>⚠️  redis SET failed for {} ({err})</yellow>", self.name);
+        }
     }
 
     async fn store_in_db(&self, pool_conn: PoolConnection<Sqlite>) -> Result<(), sqlx::Error> {
@@ -373,17 +674,25 @@ impl ContainerHealth {
         Ok(())
     }
 
-    async fn store_in_history_db(
-        &self,
-        pool_conn: PoolConnection<Sqlite>,
-    ) -> Result<(), sqlx::Error> {
+    /// appends a history row for this container rather than replacing the previous one, so
+    /// `History`/`trends` has an actual timeline to aggregate over
+    async fn store_in_history_db(&self, pool_conn: PoolConnection<Sqlite>) -> Result<(), sqlx::Error> {
         let _add_container_history_query = sqlx::query(
             "
-                insert or replace into container_history values (?,?,?,?,?) returning *;
+                insert into container_history
+                    (id, name, status, cpu_percent, memory_percent, restart_count, uptime, timestamp)
+                values (?,?,?,?,?,?,?,?)
+                returning *;
                 ",
         )
         .bind(&self.id)
         .bind(&self.name)
+        .bind(self.status.to_string())
+        .bind(self.cpu_percent)
+        .bind(self.memory_percent)
+        .bind(self.restart_count)
+        .bind(&self.uptime)
+        .bind(chrono::Utc::now().timestamp())
         .execute(&mut pool_conn.detach())
         .await?;
 
@@ -391,6 +700,96 @@ impl ContainerHealth {
     }
 }
 
+/// a single `container_history` row, decoded centrally so every history query shares the
+/// same column-to-field mapping
+#[derive(sqlx::FromRow)]
+struct HistoryRow {
+    #[allow(dead_code)]
+    id: String,
+    #[allow(dead_code)]
+    name: String,
+    status: String,
+    cpu_percent: f64,
+    memory_percent: f64,
+    restart_count: i64,
+    #[allow(dead_code)]
+    uptime: String,
+    #[allow(dead_code)]
+    timestamp: i64,
+}
+
+async fn fetch_history(
+    pool: &sqlx::Pool<Sqlite>,
+    name: &str,
+    since: i64,
+) -> Result<Vec<HistoryRow>, sqlx::Error> {
+    sqlx::query_as::<_, HistoryRow>(
+        "select id, name, status, cpu_percent, memory_percent, restart_count, uptime, timestamp
+         from container_history
+         where name = ? and timestamp >= ?
+         order by timestamp asc;",
+    )
+    .bind(name)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+}
+
+/// summary trends computed over a window of `container_history` rows
+struct HistoryTrends {
+    samples: usize,
+    avg_cpu_percent: f64,
+    peak_cpu_percent: f64,
+    avg_memory_percent: f64,
+    peak_memory_percent: f64,
+    state_transitions: usize,
+    restarts_observed: i64,
+}
+
+impl HistoryTrends {
+    fn from_rows(rows: &[HistoryRow]) -> Option<Self> {
+        if rows.is_empty() {
+            return None;
+        }
+
+        let samples = rows.len();
+        let avg_cpu_percent = rows.iter().map(|r| r.cpu_percent).sum::<f64>() / samples as f64;
+        let peak_cpu_percent = rows.iter().map(|r| r.cpu_percent).fold(f64::MIN, f64::max);
+        let avg_memory_percent =
+            rows.iter().map(|r| r.memory_percent).sum::<f64>() / samples as f64;
+        let peak_memory_percent = rows.iter().map(|r| r.memory_percent).fold(f64::MIN, f64::max);
+        let state_transitions = rows.windows(2).filter(|pair| pair[0].status != pair[1].status).count();
+        let restarts_observed = rows.iter().map(|r| r.restart_count).max().unwrap_or(0)
+            - rows.iter().map(|r| r.restart_count).min().unwrap_or(0);
+
+        Some(Self {
+            samples,
+            avg_cpu_percent,
+            peak_cpu_percent,
+            avg_memory_percent,
+            peak_memory_percent,
+            state_transitions,
+            restarts_observed,
+        })
+    }
+}
+
+impl fmt::Display for HistoryTrends {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "samples: {} | avg cpu: {:.1}% | peak cpu: {:.1}% | avg mem: {:.1}% | peak mem: {:.1}% | state transitions: {} | restarts observed: {}",
+            self.samples,
+            self.avg_cpu_percent,
+            self.peak_cpu_percent,
+            self.avg_memory_percent,
+            self.peak_memory_percent,
+            self.state_transitions,
+            self.restarts_observed
+        )
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
@@ -399,16 +798,30 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let pool = setup_sqlite_db().await;
 
-    cprintln!("🔌 Connecting to Redis...");
+    cprintln!("🔌 Setting up Redis pool...");
     let redis_client = Client::open("redis://127.0.0.1/")?;
-    let redis_conn = redis_client.get_connection()?;
-    cprintln!("<green>✅ Redis connected!</green>");
+    // `build_unchecked` skips the initial connection attempt `build` makes, so a Redis
+    // that's down at startup doesn't abort the whole process before any subcommand runs -
+    // the pool degrades the same way `from_cache`/`store_in_cache` already tolerate later
+    let redis_pool = Pool::builder().build_unchecked(RedisConnectionManager {
+        client: redis_client,
+    });
+    cprintln!("<green>✅ Redis pool ready!</green>");
 
     match cli.command {
         CliCommands::Monitor {
             name,
+            label,
+            health,
             cache_ttl,
             watch,
+            auto_restart,
+            restart_label,
+            unhealthy_timeout,
+            cpu_threshold,
+            mem_threshold,
+            restart_threshold,
+            webhook_url,
         } => {
             match check_docker_running() {
                 Ok(_) => {}
@@ -422,10 +835,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             cprintln!("<green>✅ Docker is running!</green>");
             cprintln!("<blue>Monitoring containers...</blue>");
 
-            let container_names = match name.clone() {
-                Some(names) if !names.is_empty() => names,
+            let container_names = match (name, label) {
+                (Some(names), _) if !names.is_empty() => names,
+                (_, Some(labels)) if !labels.is_empty() => {
+                    let docker = Docker::connect_with_defaults()?;
+                    list_containers_by_label(&docker, &labels, health.as_deref()).await?
+                }
                 _ => {
-                    cprintln!("<red>no container names supplied. add names with argument --name <<NAME>></red>");
+                    cprintln!("<red>no container names or labels supplied. add names with --name <<NAME>> or select by --label <<KEY=VALUE>></red>");
                     return Ok(());
                 }
             };
@@ -439,9 +856,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 // }
             }
 
-            monitor_containers(name.unwrap(), pool, redis_conn, cache_ttl, watch).await?;
+            let thresholds = HealthThresholds {
+                cpu_percent: cpu_threshold,
+                memory_percent: mem_threshold,
+                restart_count: restart_threshold,
+            };
+            let alerter: Option<SharedAlerter> = webhook_url
+                .map(|url| std::sync::Arc::new(WebhookAlerter::new(url)) as SharedAlerter);
+
+            monitor_containers(
+                container_names,
+                pool,
+                redis_pool,
+                cache_ttl,
+                watch,
+                auto_restart,
+                restart_label,
+                unhealthy_timeout,
+                thresholds,
+                alerter,
+            )
+            .await?;
         }
-        CliCommands::MonitorAll { cache_ttl, watch } => {
+        CliCommands::MonitorAll {
+            cache_ttl,
+            watch,
+            auto_restart,
+            restart_label,
+            unhealthy_timeout,
+            cpu_threshold,
+            mem_threshold,
+            restart_threshold,
+            webhook_url,
+        } => {
             match check_docker_running() {
                 Ok(_) => {}
                 Err(e) => {
@@ -459,7 +906,84 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 return Ok(());
             }
 
-            monitor_containers(container_names, pool, redis_conn, cache_ttl, watch).await?;
+            let thresholds = HealthThresholds {
+                cpu_percent: cpu_threshold,
+                memory_percent: mem_threshold,
+                restart_count: restart_threshold,
+            };
+            let alerter: Option<SharedAlerter> = webhook_url
+                .map(|url| std::sync::Arc::new(WebhookAlerter::new(url)) as SharedAlerter);
+
+            if watch {
+                // the fixed container list above is not used past the empty check; watch mode
+                // tracks membership live off the Docker events stream so newly created
+                // containers are picked up without a restart
+                watch_all_containers(
+                    pool,
+                    redis_pool,
+                    cache_ttl,
+                    auto_restart,
+                    restart_label,
+                    unhealthy_timeout,
+                    thresholds,
+                    alerter,
+                )
+                .await?;
+            } else {
+                monitor_containers(
+                    container_names,
+                    pool,
+                    redis_pool,
+                    cache_ttl,
+                    watch,
+                    auto_restart,
+                    restart_label,
+                    unhealthy_timeout,
+                    thresholds,
+                    alerter,
+                )
+                .await?;
+            }
+        }
+        CliCommands::Dashboard { name, refresh_secs } => {
+            match check_docker_running() {
+                Ok(_) => {}
+                Err(e) => {
+                    cprintln!("<red>❌ Docker is not running</red>");
+                    cprintln!("<red>Error:</red> {}", e);
+                    return Ok(());
+                }
+            };
+
+            let container_names = match name {
+                Some(names) if !names.is_empty() => names,
+                _ => get_all_containers()?,
+            };
+
+            if container_names.is_empty() {
+                cprintln!("<yellow>No containers found on your machine.</yellow>");
+                return Ok(());
+            }
+
+            tui::run(container_names, Duration::from_secs(refresh_secs)).await?;
+        }
+        CliCommands::History { name, since } => {
+            let since_timestamp = chrono::Utc::now().timestamp() - since;
+            let rows = fetch_history(&pool, &name, since_timestamp).await?;
+
+            match HistoryTrends::from_rows(&rows) {
+                Some(trends) => {
+                    cprintln!("<blue>📈 trends for {} (last {}s)</blue>", name, since);
+                    println!("{trends}");
+                }
+                None => {
+                    cprintln!(
+                        "<yellow>no history recorded for {} in the last {}s</yellow>",
+                        name,
+                        since
+                    );
+                }
+            }
         }
         CliCommands::Wipe => {
             // delete the database file
@@ -475,47 +999,434 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn monitor_containers(
     container_names: Vec<String>,
     pool: sqlx::Pool<Sqlite>,
-    mut redis_conn: redis::Connection,
+    redis_pool: RedisPool,
     cache_ttl: u64,
     watch: bool,
+    auto_restart: bool,
+    restart_label: String,
+    unhealthy_timeout: u64,
+    thresholds: HealthThresholds,
+    alerter: Option<SharedAlerter>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let docker = Docker::connect_with_defaults()?;
+    let unhealthy_timeout = Duration::from_secs(unhealthy_timeout);
+
+    // tracks, per container, when it was first observed unhealthy so a transient
+    // blip doesn't immediately trigger a restart
+    let mut unhealthy_since: HashMap<String, Instant> = HashMap::new();
+
     loop {
         for name in &container_names {
-            let cache_key = format!("health-data:{}", name);
+            refresh_container(
+                &docker,
+                &pool,
+                &redis_pool,
+                cache_ttl,
+                name,
+                auto_restart,
+                &restart_label,
+                unhealthy_timeout,
+                &mut unhealthy_since,
+                &thresholds,
+                alerter.as_ref(),
+            )
+            .await?;
+        }
+        if !watch {
+            break;
+        };
+
+        // add waiting 5 seconds for each watch (todo: review how many seconds might be appropiate)
+        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    }
 
-            match ContainerHealth::from_cache(&cache_key, &mut redis_conn)? {
-                Some(health) => {
-                    println!("(from cache) {health}");
+    Ok(())
+}
+
+/// fetches (or serves from cache) a single container's health, reports it, persists it,
+/// and feeds it through the auto-restart watchdog. Shared between the fixed-list poller
+/// and the event-driven `watch_all_containers` loop.
+#[allow(clippy::too_many_arguments)]
+async fn refresh_container(
+    docker: &Docker,
+    pool: &sqlx::Pool<Sqlite>,
+    redis_pool: &RedisPool,
+    cache_ttl: u64,
+    name: &str,
+    auto_restart: bool,
+    restart_label: &str,
+    unhealthy_timeout: Duration,
+    unhealthy_since: &mut HashMap<String, Instant>,
+    thresholds: &HealthThresholds,
+    alerter: Option<&SharedAlerter>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let cache_key = format!("health-data:{}", name);
+
+    // fetched once per refresh, before this tick's own write, so it reflects the prior tick
+    // regardless of whether this tick's health comes from cache or a live Docker fetch
+    let previous_status = fetch_previous_status(pool, name).await?;
+
+    let health = match ContainerHealth::from_cache(&cache_key, redis_pool).await {
+        Some(health) => {
+            println!("(from cache) {health}");
+            health
+        }
+        None => {
+            // No cached data found (or the cache is unavailable/stale), proceed to fetch
+            // fresh data straight from Docker
+            let container_health_info = ContainerHealth::new(name, docker, thresholds).await?;
+            container_health_info.store_in_cache(redis_pool, cache_ttl).await;
+
+            println!("{container_health_info}");
+
+            container_health_info
+        }
+    };
+
+    // recorded on every refresh (cached or fresh), not only on a live Docker fetch, so
+    // `History`/trends aren't gutted by the default cache TTL
+    let conn_2 = pool.acquire().await?;
+    let conn_3 = pool.acquire().await?;
+    health.store_in_db(conn_2).await?;
+    health.store_in_history_db(conn_3).await?;
+
+    if let Some(alerter) = alerter {
+        // spawned so a slow/unreachable webhook endpoint stalls only its own alert, not the
+        // rest of this container's refresh or the next container in the poll loop
+        let alerter = alerter.clone();
+        let health = health.clone();
+        let thresholds = *thresholds;
+        let previous_status = previous_status.clone();
+        tokio::spawn(async move {
+            dispatch_alert_if_newly_unhealthy(
+                alerter.as_ref(),
+                &health,
+                previous_status.as_deref(),
+                &thresholds,
+            )
+            .await;
+        });
+    }
+
+    if auto_restart {
+        maybe_auto_restart(
+            docker,
+            pool,
+            &health,
+            restart_label,
+            unhealthy_timeout,
+            unhealthy_since,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// membership/refresh signal produced by the Docker events subscription in
+/// `watch_all_containers`
+enum ContainerWatchEvent {
+    Added(String),
+    Removed(String),
+    Refresh(String),
+}
+
+/// watches every container on the machine, following the live container set off a Docker
+/// events subscription instead of the name list captured at startup, so containers created
+/// after the CLI starts are picked up automatically. A periodic tick still drives a stats
+/// refresh fallback; Ctrl-C cleanly stops both the event task and this loop.
+#[allow(clippy::too_many_arguments)]
+async fn watch_all_containers(
+    pool: sqlx::Pool<Sqlite>,
+    redis_pool: RedisPool,
+    cache_ttl: u64,
+    auto_restart: bool,
+    restart_label: String,
+    unhealthy_timeout: u64,
+    thresholds: HealthThresholds,
+    alerter: Option<SharedAlerter>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let docker = Docker::connect_with_defaults()?;
+    let unhealthy_timeout = Duration::from_secs(unhealthy_timeout);
+
+    let mut tracked: std::collections::HashSet<String> =
+        get_all_containers()?.into_iter().collect();
+
+    let (event_tx, mut event_rx) = tokio::sync::mpsc::channel::<ContainerWatchEvent>(64);
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::mpsc::channel::<()>(1);
+
+    let events_docker = docker.clone();
+    let events_task = tokio::spawn(async move {
+        let mut filters: HashMap<String, Vec<String>> = HashMap::new();
+        filters.insert("type".to_string(), vec!["container".to_string()]);
+        filters.insert(
+            "event".to_string(),
+            vec![
+                "create".to_string(),
+                "start".to_string(),
+                "destroy".to_string(),
+                "die".to_string(),
+                "health_status".to_string(),
+            ],
+        );
+
+        let events_options = bollard::query_parameters::EventsOptions {
+            since: None,
+            until: None,
+            filters: Some(filters),
+        };
+
+        let mut event_stream = events_docker.events(Some(events_options));
+
+        loop {
+            let event = match event_stream.next().await {
+                Some(Ok(event)) => event,
+                Some(Err(err)) => {
+                    // a transient decode/reconnect hiccup shouldn't kill membership
+                    // tracking for the rest of the run
+                    eprintln!("watch: docker events stream error: {err}");
                     continue;
                 }
                 None => {
-                    // No cached data found, proceed to fetch fresh data
-                    // Proceed to fetch fresh data even if cache retrieval fails
-                    let docker = Docker::connect_with_defaults()?;
-                    let container_health_info = ContainerHealth::new(name, &docker).await?;
-                    let conn_2 = pool.acquire().await?;
+                    eprintln!("watch: docker events stream ended, container create/destroy tracking stopped");
+                    break;
+                }
+            };
+
+            let Some(name) = event
+                .actor
+                .as_ref()
+                .and_then(|actor| actor.attributes.as_ref())
+                .and_then(|attributes| attributes.get("name"))
+                .cloned()
+            else {
+                continue;
+            };
 
-                    container_health_info.store_in_db(conn_2).await?;
-                    container_health_info.store_in_cache(&mut redis_conn, cache_ttl)?;
+            // docker reports this as `health_status: healthy`/`health_status: unhealthy`,
+            // not the bare action name
+            let watch_event = match event.action.as_deref() {
+                Some("create") => ContainerWatchEvent::Added(name),
+                Some("destroy") => ContainerWatchEvent::Removed(name),
+                Some(action) if action == "start" || action == "die" || action.starts_with("health_status") => {
+                    ContainerWatchEvent::Refresh(name)
+                }
+                _ => continue,
+            };
+
+            if event_tx.send(watch_event).await.is_err() {
+                break;
+            }
+        }
+    });
 
-                    println!("{container_health_info}");
+    tokio::spawn(async move {
+        let _ = tokio::signal::ctrl_c().await;
+        let _ = shutdown_tx.send(()).await;
+    });
+
+    let mut unhealthy_since: HashMap<String, Instant> = HashMap::new();
+    let mut stats_tick = tokio::time::interval(Duration::from_secs(5));
+
+    loop {
+        tokio::select! {
+            _ = shutdown_rx.recv() => {
+                cprintln!("<yellow>🛑 stopping watch mode...</yellow>");
+                break;
+            }
+            Some(event) = event_rx.recv() => match event {
+                ContainerWatchEvent::Added(name) => {
+                    cprintln!("<blue>➕ new container detected, now watching {}</blue>", name);
+                    tracked.insert(name);
+                }
+                ContainerWatchEvent::Removed(name) => {
+                    cprintln!("<yellow>➖ {} was destroyed, no longer watching it</yellow>", name);
+                    tracked.remove(&name);
+                    unhealthy_since.remove(&name);
+                }
+                ContainerWatchEvent::Refresh(name) => {
+                    if tracked.contains(&name) {
+                        // a container that's e.g. been removed between its event and now
+                        // shouldn't take the rest of the tracked set down with it
+                        if let Err(err) = refresh_container(
+                            &docker,
+                            &pool,
+                            &redis_pool,
+                            cache_ttl,
+                            &name,
+                            auto_restart,
+                            &restart_label,
+                            unhealthy_timeout,
+                            &mut unhealthy_since,
+                            &thresholds,
+                            alerter.as_ref(),
+                        )
+                        .await
+                        {
+                            eprintln!("watch: failed to refresh {name}: {err}");
+                        }
+                    }
+                }
+            },
+            _ = stats_tick.tick() => {
+                for name in tracked.clone() {
+                    if let Err(err) = refresh_container(
+                        &docker,
+                        &pool,
+                        &redis_pool,
+                        cache_ttl,
+                        &name,
+                        auto_restart,
+                        &restart_label,
+                        unhealthy_timeout,
+                        &mut unhealthy_since,
+                        &thresholds,
+                        alerter.as_ref(),
+                    )
+                    .await
+                    {
+                        eprintln!("watch: failed to refresh {name}: {err}");
+                    }
                 }
             }
         }
-        if !watch {
-            break;
-        };
+    }
 
-        // add waiting 5 seconds for each watch (todo: review how many seconds might be appropiate)
-        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+    events_task.abort();
+
+    Ok(())
+}
+
+/// restarts `health`'s container once it has been continuously unhealthy for longer than
+/// `unhealthy_timeout`, provided it carries `restart_label`. Containers that recover before
+/// the timeout have their unhealthy timer cleared so a transient blip never triggers a restart.
+async fn maybe_auto_restart(
+    docker: &Docker,
+    pool: &sqlx::Pool<Sqlite>,
+    health: &ContainerHealth,
+    restart_label: &str,
+    unhealthy_timeout: Duration,
+    unhealthy_since: &mut HashMap<String, Instant>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !matches!(health.status, HealthStatus::Unhealthy) {
+        unhealthy_since.remove(&health.name);
+        return Ok(());
+    }
+
+    if !health.labels.contains_key(restart_label) {
+        return Ok(());
     }
 
+    let first_unhealthy_at = *unhealthy_since
+        .entry(health.name.clone())
+        .or_insert_with(Instant::now);
+
+    if first_unhealthy_at.elapsed() < unhealthy_timeout {
+        return Ok(());
+    }
+
+    docker.restart_container(&health.name, None).await?;
+    unhealthy_since.remove(&health.name);
+
+    let restart_count = persist_auto_restart_count(pool, &health.name).await?;
+
+    cprintln!(
+        "<yellow>🔁 auto-restarted {} after {}s unhealthy (restart #{})</yellow>",
+        health.name,
+        unhealthy_timeout.as_secs(),
+        restart_count
+    );
+
     Ok(())
 }
 
+/// records a restart the watchdog just performed for `name`, returning its new all-time
+/// count. Persisted in sqlite (instead of an in-process counter) so the count still means
+/// something across monitor restarts.
+async fn persist_auto_restart_count(
+    pool: &sqlx::Pool<Sqlite>,
+    name: &str,
+) -> Result<i64, sqlx::Error> {
+    sqlx::query(
+        "insert into auto_restarts (name, restart_count) values (?, 1)
+         on conflict(name) do update set restart_count = restart_count + 1;",
+    )
+    .bind(name)
+    .execute(pool)
+    .await?;
+
+    let (restart_count,): (i64,) =
+        sqlx::query_as("select restart_count from auto_restarts where name = ?;")
+            .bind(name)
+            .fetch_one(pool)
+            .await?;
+
+    Ok(restart_count)
+}
+
+/// the `status` last persisted for `name` in the `containers` table, used to detect the
+/// edge transition into `Unhealthy` rather than re-firing an alert every tick
+async fn fetch_previous_status(
+    pool: &sqlx::Pool<Sqlite>,
+    name: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    let row: Option<(String,)> = sqlx::query_as("select status from containers where name = ?;")
+        .bind(name)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|(status,)| status))
+}
+
+/// fires `alerter` only on the edge transition into `Unhealthy` (i.e. the previously stored
+/// status wasn't already `Unhealthy`), so a steadily-unhealthy container doesn't re-alert
+/// every tick. Dispatch failures are logged and otherwise ignored.
+async fn dispatch_alert_if_newly_unhealthy(
+    alerter: &(dyn Alerter + Send + Sync),
+    health: &ContainerHealth,
+    previous_status: Option<&str>,
+    thresholds: &HealthThresholds,
+) {
+    if !matches!(health.status, HealthStatus::Unhealthy) {
+        return;
+    }
+
+    if previous_status == Some(HealthStatus::Unhealthy.to_string().as_str()) {
+        return;
+    }
+
+    // a container can transition into `Unhealthy` via its own `HEALTHCHECK` (native status,
+    // preferred over the heuristic since request #7) without ever breaching a threshold;
+    // describe those as a `healthcheck` metric instead of silently dropping the alert
+    let (metric, value, threshold) = ContainerHealth::breached_metric(
+        health.cpu_percent,
+        health.memory_percent,
+        health.restart_count,
+        thresholds,
+    )
+    .unwrap_or(("healthcheck", 1.0, 0.0));
+
+    let payload = AlertPayload {
+        container: health.name.clone(),
+        container_state: health.container_state.to_string(),
+        metric: metric.to_string(),
+        value,
+        threshold,
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+
+    if let Err(err) = alerter.alert(&payload).await {
+        cprintln!(
+            "<yellow>⚠️  alert dispatch failed for {} ({err})</yellow>",
+            health.name
+        );
+    }
+}
+
 fn get_all_containers() -> Result<Vec<String>, Box<dyn std::error::Error>> {
     let ps_output = Command::new("docker")
         .args(["ps", "-a", "--format", "{{.Names}}"])
@@ -532,6 +1443,36 @@ fn get_all_containers() -> Result<Vec<String>, Box<dyn std::error::Error>> {
     Ok(container_names)
 }
 
+/// resolves container names from bollard's `list_containers`, filtered by label (and
+/// optionally by docker health status) instead of requiring the caller to enumerate names
+async fn list_containers_by_label(
+    docker: &Docker,
+    labels: &[String],
+    health: Option<&str>,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut filters: HashMap<String, Vec<String>> = HashMap::new();
+    filters.insert("label".to_string(), labels.to_vec());
+
+    if let Some(health) = health {
+        filters.insert("health".to_string(), vec![health.to_string()]);
+    }
+
+    let options = bollard::query_parameters::ListContainersOptions {
+        all: true,
+        limit: None,
+        size: false,
+        filters: Some(filters),
+    };
+
+    let containers = docker.list_containers(Some(options)).await?;
+
+    Ok(containers
+        .into_iter()
+        .filter_map(|container| container.names.and_then(|names| names.into_iter().next()))
+        .map(|name| name.trim_start_matches('/').to_string())
+        .collect())
+}
+
 /// takes a container name an validates if docker recognizes it
 fn is_container_in_list(container_name: &str) -> bool {
     let mut stat: bool = false;
@@ -596,12 +1537,12 @@ async fn setup_sqlite_db() -> sqlx::Pool<Sqlite> {
     let _setup_container_history_table_query = sqlx::query(
         "
         create table if not exists container_history (
-            id text unique,
-            name text unique,
+            id text,
+            name text,
             status text,
             cpu_percent real,
             memory_percent real,
-            restart_count text,
+            restart_count integer,
             uptime text,
             timestamp integer
         );
@@ -611,6 +1552,26 @@ async fn setup_sqlite_db() -> sqlx::Pool<Sqlite> {
     .await
     .unwrap();
 
+    let conn_3 = pool
+        .clone()
+        .acquire()
+        .await
+        .expect("failed to acquire connection pool");
+
+    // survives a monitor restart, unlike a stack-local counter: the auto-restart watchdog's
+    // count needs to mean something across runs, not just within one
+    let _setup_auto_restarts_table_query = sqlx::query(
+        "
+        create table if not exists auto_restarts (
+            name text primary key,
+            restart_count integer
+        );
+        ",
+    )
+    .execute(&mut conn_3.detach())
+    .await
+    .unwrap();
+
     pool
 }
 
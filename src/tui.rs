@@ -0,0 +1,367 @@
+//! full-screen interactive dashboard for the `Dashboard` subcommand: a live table of
+//! container health plus CPU/memory sparklines, with per-container actions gated by
+//! `ContainerState` (mirroring which docker subcommands apply to which state).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use bollard::Docker;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table, TableState};
+use ratatui::Terminal;
+use tokio::sync::mpsc;
+
+use crate::{ContainerHealth, ContainerState, HealthThresholds};
+
+/// how many samples each rolling CPU/memory sparkline keeps
+const HISTORY_LEN: usize = 60;
+
+/// an action the dashboard can dispatch against the selected container
+#[derive(Clone, Copy, Debug)]
+enum ContainerAction {
+    Start,
+    Stop,
+    Pause,
+    Unpause,
+    Restart,
+}
+
+impl ContainerAction {
+    /// the actions offered for a container in `state`, mirroring how `ContainerState` gates
+    /// which docker subcommands apply
+    fn available_for(state: &ContainerState) -> &'static [ContainerAction] {
+        match state {
+            ContainerState::Dead | ContainerState::Exited | ContainerState::Stopped => {
+                &[ContainerAction::Start, ContainerAction::Restart]
+            }
+            ContainerState::Running | ContainerState::Restarting => &[
+                ContainerAction::Stop,
+                ContainerAction::Pause,
+                ContainerAction::Restart,
+            ],
+            ContainerState::Paused => &[ContainerAction::Unpause],
+            ContainerState::Created | ContainerState::Removing => &[],
+        }
+    }
+
+    fn key(&self) -> char {
+        match self {
+            ContainerAction::Start => 's',
+            ContainerAction::Stop => 't',
+            ContainerAction::Pause => 'p',
+            ContainerAction::Unpause => 'u',
+            ContainerAction::Restart => 'r',
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ContainerAction::Start => "start",
+            ContainerAction::Stop => "stop",
+            ContainerAction::Pause => "pause",
+            ContainerAction::Unpause => "unpause",
+            ContainerAction::Restart => "restart",
+        }
+    }
+
+    fn from_key(key: char, state: &ContainerState) -> Option<Self> {
+        Self::available_for(state)
+            .iter()
+            .copied()
+            .find(|action| action.key() == key)
+    }
+}
+
+/// rolling CPU/memory history for one container, rendered as sparklines
+struct History {
+    cpu: Vec<u64>,
+    memory: Vec<u64>,
+}
+
+impl History {
+    fn new() -> Self {
+        Self {
+            cpu: Vec::with_capacity(HISTORY_LEN),
+            memory: Vec::with_capacity(HISTORY_LEN),
+        }
+    }
+
+    fn push(&mut self, cpu_percent: f32, memory_percent: f32) {
+        if self.cpu.len() == HISTORY_LEN {
+            self.cpu.remove(0);
+        }
+        if self.memory.len() == HISTORY_LEN {
+            self.memory.remove(0);
+        }
+        self.cpu.push(cpu_percent.round() as u64);
+        self.memory.push(memory_percent.round() as u64);
+    }
+}
+
+struct ContainerRow {
+    health: ContainerHealth,
+    history: History,
+}
+
+/// drives the dashboard: full-screen terminal UI on one task, Docker I/O on another,
+/// connected by a command channel so the UI never blocks on a bollard call
+pub async fn run(
+    container_names: Vec<String>,
+    refresh: Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let docker = Docker::connect_with_defaults()?;
+
+    let (action_tx, action_rx) = mpsc::channel::<(String, ContainerAction)>(16);
+    let dispatcher_docker = docker.clone();
+    tokio::spawn(dispatch_actions(dispatcher_docker, action_rx));
+
+    let (health_tx, mut health_rx) = mpsc::channel::<(String, ContainerHealth)>(64);
+    let poller_docker = docker.clone();
+    let poller_names = container_names.clone();
+    tokio::spawn(poll_health(poller_docker, poller_names, refresh, health_tx));
+
+    let mut rows: HashMap<String, ContainerRow> = HashMap::new();
+    for name in &container_names {
+        rows.insert(
+            name.clone(),
+            ContainerRow {
+                health: ContainerHealth::default(),
+                history: History::new(),
+            },
+        );
+    }
+
+    enable_raw_mode()?;
+    let mut stdout = std::io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(0));
+
+    let result = loop {
+        // drains whatever the poller task has produced since the last frame; never blocks,
+        // so a slow Docker round trip stalls only the poller, not navigation/rendering
+        while let Ok((name, health)) = health_rx.try_recv() {
+            if let Some(row) = rows.get_mut(&name) {
+                row.history.push(health.cpu_percent, health.memory_percent);
+                row.health = health;
+            }
+        }
+
+        let selected_name = table_state
+            .selected()
+            .and_then(|index| container_names.get(index))
+            .cloned();
+
+        terminal.draw(|frame| draw(frame, &container_names, &rows, &table_state))?;
+
+        if event::poll(Duration::from_millis(150))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => break Ok(()),
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        let next = table_state.selected().unwrap_or(0) + 1;
+                        table_state.select(Some(next.min(container_names.len().saturating_sub(1))));
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        let current = table_state.selected().unwrap_or(0);
+                        table_state.select(Some(current.saturating_sub(1)));
+                    }
+                    KeyCode::Char(c) => {
+                        if let Some(name) = selected_name {
+                            if let Some(row) = rows.get(&name) {
+                                if let Some(action) =
+                                    ContainerAction::from_key(c, &row.health.container_state)
+                                {
+                                    let _ = action_tx.send((name, action)).await;
+                                }
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+/// periodically refreshes every container's health/stats on its own task, sending each
+/// result back over `health_tx`, so a slow Docker round trip never stalls navigation/
+/// rendering the way running this inline in the render loop did
+async fn poll_health(
+    docker: Docker,
+    container_names: Vec<String>,
+    refresh: Duration,
+    health_tx: mpsc::Sender<(String, ContainerHealth)>,
+) {
+    let thresholds = HealthThresholds::default();
+    let mut tick = tokio::time::interval(refresh);
+
+    loop {
+        tick.tick().await;
+
+        for name in &container_names {
+            match ContainerHealth::new(name, &docker, &thresholds).await {
+                Ok(health) => {
+                    if health_tx.send((name.clone(), health)).await.is_err() {
+                        return;
+                    }
+                }
+                Err(err) => {
+                    eprintln!("dashboard: failed to refresh {name}: {err}");
+                }
+            }
+        }
+    }
+}
+
+/// performs the bollard call for a dispatched action; runs on its own task so the render
+/// loop never blocks on Docker I/O
+async fn dispatch_actions(
+    docker: Docker,
+    mut action_rx: mpsc::Receiver<(String, ContainerAction)>,
+) {
+    while let Some((name, action)) = action_rx.recv().await {
+        let result = match action {
+            ContainerAction::Start => {
+                docker
+                    .start_container(
+                        &name,
+                        None::<bollard::query_parameters::StartContainerOptions>,
+                    )
+                    .await
+            }
+            ContainerAction::Stop => {
+                docker
+                    .stop_container(&name, None::<bollard::query_parameters::StopContainerOptions>)
+                    .await
+            }
+            ContainerAction::Pause => docker.pause_container(&name).await,
+            ContainerAction::Unpause => docker.unpause_container(&name).await,
+            ContainerAction::Restart => docker.restart_container(&name, None).await,
+        };
+
+        if let Err(err) = result {
+            eprintln!("dashboard: failed to {} {name}: {err}", action.label());
+        }
+    }
+}
+
+fn draw(
+    frame: &mut ratatui::Frame,
+    container_names: &[String],
+    rows: &HashMap<String, ContainerRow>,
+    table_state: &TableState,
+) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(6),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(3),
+        ])
+        .split(frame.area());
+
+    let table_rows: Vec<Row> = container_names
+        .iter()
+        .map(|name| {
+            let row = rows.get(name);
+            let health = row.map(|r| &r.health);
+
+            Row::new(vec![
+                Cell::from(name.clone()),
+                Cell::from(
+                    health
+                        .map(|h| h.container_state.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(
+                    health
+                        .map(|h| h.status.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                ),
+                Cell::from(health.map(|h| h.restart_count.to_string()).unwrap_or_default()),
+                Cell::from(health.map(|h| h.uptime.clone()).unwrap_or_default()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        table_rows,
+        [
+            Constraint::Percentage(30),
+            Constraint::Percentage(15),
+            Constraint::Percentage(20),
+            Constraint::Percentage(15),
+            Constraint::Percentage(20),
+        ],
+    )
+    .header(
+        Row::new(vec!["name", "state", "health", "restarts", "uptime"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(Block::default().title("containers").borders(Borders::ALL))
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(table, layout[0], &mut table_state.clone());
+
+    let selected_row = table_state
+        .selected()
+        .and_then(|index| container_names.get(index))
+        .and_then(|name| rows.get(name));
+
+    let cpu_data = selected_row.map(|row| row.history.cpu.as_slice()).unwrap_or(&[]);
+    let mem_data = selected_row
+        .map(|row| row.history.memory.as_slice())
+        .unwrap_or(&[]);
+
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::default().title("cpu %").borders(Borders::ALL))
+            .data(cpu_data)
+            .style(Style::default().fg(Color::Cyan)),
+        layout[1],
+    );
+
+    frame.render_widget(
+        Sparkline::default()
+            .block(Block::default().title("memory %").borders(Borders::ALL))
+            .data(mem_data)
+            .style(Style::default().fg(Color::Magenta)),
+        layout[2],
+    );
+
+    let actions = selected_row
+        .map(|row| ContainerAction::available_for(&row.health.container_state))
+        .unwrap_or(&[]);
+
+    let actions_text: Vec<Span> = actions
+        .iter()
+        .map(|action| Span::raw(format!("[{}] {}  ", action.key(), action.label())))
+        .collect();
+
+    frame.render_widget(
+        Paragraph::new(Line::from(actions_text))
+            .block(Block::default().title("actions (↑/↓ select, q quit)").borders(Borders::ALL)),
+        layout[3],
+    );
+}